@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::str::FromStr;
 
 use itertools::Itertools;
@@ -22,6 +23,76 @@ pub enum RuleSelector {
         prefix: RuleCodePrefix,
         redirected_from: Option<&'static str>,
     },
+    /// A selector that didn't parse as any of the other variants, kept around instead of rejected
+    /// outright so that config deserialization can be lenient (see [`RuleSelector::from_str_lenient`]).
+    Unknown(String),
+    Category(RuleCategory),
+    /// A `*`-wildcard selector, e.g. `E5*` or `UP00*`, matched against each rule's full
+    /// `{prefix}{code}` string.
+    Pattern {
+        /// The glob as written in the config, kept around so we can re-serialize it verbatim.
+        glob: String,
+        /// The part of `glob` before its first `*`, used to order this selector relative to
+        /// others in [`RuleSelector::specificity`].
+        prefix: String,
+    },
+}
+
+/// A cross-cutting group of rules that doesn't map to a single linter, e.g. "every rule that
+/// flags a security issue regardless of which linter defines it".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub enum RuleCategory {
+    Security,
+    Performance,
+}
+
+impl RuleCategory {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RuleCategory::Security => "security",
+            RuleCategory::Performance => "performance",
+        }
+    }
+}
+
+impl FromStr for RuleCategory {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RuleCategory::iter()
+            .find(|category| category.as_str() == s)
+            .ok_or(())
+    }
+}
+
+/// The categories a rule belongs to, looked up by its noqa code. Most rules belong to none.
+///
+/// This is the inverse of [`RuleSelector::Category`]: a selector like `security` expands to
+/// every rule whose code appears here tagged with [`RuleCategory::Security`].
+fn rule_categories(code: &str) -> &'static [RuleCategory] {
+    match code {
+        // flake8-bandit (`S`) rules that flag concrete security vulnerabilities.
+        "S102" | "S103" | "S104" | "S105" | "S106" | "S107" | "S108" | "S113" | "S301" | "S302"
+        | "S303" | "S304" | "S305" | "S306" | "S307" | "S308" | "S310" | "S311" | "S312"
+        | "S313" | "S314" | "S315" | "S316" | "S317" | "S318" | "S319" | "S320" | "S321"
+        | "S323" | "S324" | "S501" | "S506" | "S508" | "S509" | "S601" | "S602" | "S603"
+        | "S604" | "S605" | "S606" | "S607" | "S608" | "S609" | "S610" | "S611" | "S612"
+        | "S701" => &[RuleCategory::Security],
+        // Perflint (`PERF`) rules, which exist specifically to flag avoidable runtime cost
+        // (quadratic patterns, needless allocations, repeated lookups) rather than style or
+        // correctness. Complexity/refactor-style codes like mccabe's C901 or pylint's PLR/PLW
+        // rules deliberately aren't included here: they're about readability or correctness,
+        // not runtime cost.
+        "PERF101" | "PERF102" | "PERF203" | "PERF401" | "PERF402" => &[RuleCategory::Performance],
+        _ => &[],
+    }
+}
+
+impl Rule {
+    /// The [`RuleCategory`]s this rule belongs to, if any.
+    pub fn categories(&self) -> &'static [RuleCategory] {
+        rule_categories(&self.noqa_code().to_string())
+    }
 }
 
 impl From<Linter> for RuleSelector {
@@ -36,46 +107,225 @@ impl FromStr for RuleSelector {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s == "ALL" {
             Ok(Self::All)
+        } else if let Ok(category) = RuleCategory::from_str(s) {
+            Ok(Self::Category(category))
+        } else if let Some(star) = s.find('*') {
+            Ok(Self::Pattern {
+                glob: s.to_string(),
+                prefix: s[..star].to_string(),
+            })
         } else {
             let (s, redirected_from) = match get_redirect(s) {
                 Some((from, target)) => (target, Some(from)),
                 None => (s, None),
             };
 
-            let (linter, code) =
-                Linter::parse_code(s).ok_or_else(|| ParseError::Unknown(s.to_string()))?;
+            let (linter, code) = Linter::parse_code(s).ok_or_else(|| ParseError::Unknown {
+                given: s.to_string(),
+                suggestions: find_suggestions(s),
+            })?;
 
             if code.is_empty() {
                 return Ok(Self::Linter(linter));
             }
 
             Ok(Self::Prefix {
-                prefix: RuleCodePrefix::parse(&linter, code)
-                    .map_err(|_| ParseError::Unknown(code.to_string()))?,
+                prefix: RuleCodePrefix::parse(&linter, code).map_err(|_| ParseError::Unknown {
+                    given: code.to_string(),
+                    // Suggestions are matched against `s`, not `code`: candidates are full
+                    // `{prefix}{code}` strings, so searching with the linter prefix stripped off
+                    // would never find a match for linters with a multi-character prefix (e.g.
+                    // a typo'd `PLR912` would search for `R912` and miss `PLR0912` entirely).
+                    suggestions: find_suggestions(s),
+                })?,
                 redirected_from,
             })
         }
     }
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug)]
 pub enum ParseError {
-    #[error("Unknown rule selector `{0}`")]
     // TODO(martin): tell the user how to discover rule codes via the CLI once such a command is
     // implemented (but that should of course be done only in ruff_cli and not here)
-    Unknown(String),
+    Unknown {
+        given: String,
+        suggestions: Vec<String>,
+    },
+}
+
+impl std::error::Error for ParseError {}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Unknown { given, suggestions } => {
+                write!(f, "Unknown rule selector `{given}`")?;
+                if !suggestions.is_empty() {
+                    write!(f, "; did you mean ")?;
+                    write!(
+                        f,
+                        "{}",
+                        suggestions.iter().map(|s| format!("`{s}`")).join(", ")
+                    )?;
+                    write!(f, "?")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The fixed strings `RuleSelector::from_str` is able to parse, i.e. `"ALL"`, every
+/// `RuleCategory` name, every `RuleCodePrefix` code, and every non-empty
+/// `Linter::common_prefix()`. `*`-wildcard patterns and redirected codes are not included.
+/// Shared between the `json_schema` enum values and the "did you mean" suggestion search
+/// below, so the two can't drift apart.
+fn all_selector_candidates() -> impl Iterator<Item = String> {
+    std::iter::once("ALL".to_string())
+        .chain(RuleCategory::iter().map(|category| category.as_str().to_string()))
+        .chain(RuleCodePrefix::iter().map(|p| {
+            let prefix = p.linter().common_prefix();
+            let code = p.short_code();
+            format!("{prefix}{code}")
+        }))
+        .chain(Linter::iter().filter_map(|l| {
+            let prefix = l.common_prefix();
+            (!prefix.is_empty()).then(|| prefix.to_string())
+        }))
+}
+
+/// Returns up to 3 known selectors that are likely typos of `given`, closest first.
+///
+/// A candidate is considered only if its edit distance to `given` is at most 2 (or
+/// `ceil(len / 3)` for longer codes, so a typo in a long code like `PLW0120` still surfaces
+/// reasonable suggestions), and only if its length doesn't differ from `given`'s by more than
+/// that same threshold, which lets us skip the expensive distance computation for obviously
+/// unrelated candidates.
+fn find_suggestions(given: &str) -> Vec<String> {
+    let threshold = std::cmp::max(2, (given.len() + 2) / 3);
+
+    let mut scored: Vec<(usize, String)> = all_selector_candidates()
+        .filter(|candidate| candidate.len().abs_diff(given.len()) <= threshold)
+        .filter_map(|candidate| {
+            let distance = edit_distance(given, &candidate);
+            (distance <= threshold).then_some((distance, candidate))
+        })
+        .collect();
+
+    scored.sort();
+    scored.into_iter().take(3).map(|(_, s)| s).collect()
+}
+
+/// Levenshtein edit distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            curr[j] = if a[i - 1] == b[j - 1] {
+                prev[j - 1]
+            } else {
+                1 + prev[j - 1].min(prev[j]).min(curr[j - 1])
+            };
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// Matches a `*`-wildcard glob against a candidate string. `*` matches any run of characters
+/// (including none); every other character must match literally.
+fn glob_matches(glob: &str, candidate: &str) -> bool {
+    let glob: Vec<char> = glob.chars().collect();
+    let candidate: Vec<char> = candidate.chars().collect();
+
+    let (mut g, mut c) = (0, 0);
+    let mut star: Option<usize> = None;
+    let mut star_candidate = 0;
+
+    while c < candidate.len() {
+        if g < glob.len() && (glob[g] == '*' || glob[g] == candidate[c]) {
+            if glob[g] == '*' {
+                star = Some(g);
+                star_candidate = c;
+                g += 1;
+            } else {
+                g += 1;
+                c += 1;
+            }
+        } else if let Some(star_g) = star {
+            g = star_g + 1;
+            star_candidate += 1;
+            c = star_candidate;
+        } else {
+            return false;
+        }
+    }
+
+    while g < glob.len() && glob[g] == '*' {
+        g += 1;
+    }
+    g == glob.len()
 }
 
 impl RuleSelector {
-    pub fn prefix_and_code(&self) -> (&'static str, &'static str) {
+    pub fn prefix_and_code(&self) -> (Cow<'static, str>, Cow<'static, str>) {
         match self {
-            RuleSelector::All => ("", "ALL"),
-            RuleSelector::Prefix { prefix, .. } => {
-                (prefix.linter().common_prefix(), prefix.short_code())
+            RuleSelector::All => (Cow::Borrowed(""), Cow::Borrowed("ALL")),
+            RuleSelector::Prefix { prefix, .. } => (
+                Cow::Borrowed(prefix.linter().common_prefix()),
+                Cow::Borrowed(prefix.short_code()),
+            ),
+            RuleSelector::Linter(l) => (Cow::Borrowed(l.common_prefix()), Cow::Borrowed("")),
+            RuleSelector::Category(category) => {
+                (Cow::Borrowed(""), Cow::Borrowed(category.as_str()))
             }
-            RuleSelector::Linter(l) => (l.common_prefix(), ""),
+            RuleSelector::Pattern { glob, .. } => (Cow::Borrowed(""), Cow::Owned(glob.clone())),
+            RuleSelector::Unknown(s) => (Cow::Borrowed(""), Cow::Owned(s.clone())),
         }
     }
+
+    /// Like [`FromStr::from_str`], but never fails: an unrecognized selector is kept around as
+    /// [`RuleSelector::Unknown`] instead of being rejected, so that a config referencing a
+    /// selector from a newer (or older) Ruff doesn't hard-fail the whole config.
+    pub fn from_str_lenient(s: &str) -> Self {
+        FromStr::from_str(s).unwrap_or_else(|_| RuleSelector::Unknown(s.to_string()))
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, RuleSelector::Unknown(_))
+    }
+}
+
+/// Collects the selectors that failed to parse into a single warning message, or `None` if
+/// every selector was recognized. Intended to be called once, after all selectors configured
+/// for a run have been collected, so the user sees one combined warning instead of one per
+/// selector.
+pub fn unknown_selectors_warning<'a>(
+    selectors: impl IntoIterator<Item = &'a RuleSelector>,
+) -> Option<String> {
+    let unknown: Vec<String> = selectors
+        .into_iter()
+        .filter(|selector| selector.is_unknown())
+        .map(|selector| selector.prefix_and_code().1.into_owned())
+        .collect();
+
+    if unknown.is_empty() {
+        None
+    } else {
+        Some(format!(
+            "Unknown rule selector{} (ignored): {}",
+            if unknown.len() == 1 { "" } else { "s" },
+            unknown.join(", ")
+        ))
+    }
 }
 
 impl Serialize for RuleSelector {
@@ -98,6 +348,13 @@ impl<'de> Deserialize<'de> for RuleSelector {
         // FromStr::from_str(s).map_err(de::Error::custom)
         // here because the toml crate apparently doesn't support that
         // (as of toml v0.6.0 running `cargo test` failed with the above two lines)
+        //
+        // This goes through `from_str_lenient` rather than `FromStr::from_str`: a
+        // `pyproject.toml`'s `select`/`ignore` lists are exactly the place a selector from a
+        // newer Ruff shows up on an older binary, and that must not hard-fail the whole config.
+        // Callers that do want a hard error for an unrecognized selector (e.g. parsing a single
+        // `--select` CLI argument) should use `FromStr::from_str` directly instead of going
+        // through `Deserialize`.
         deserializer.deserialize_str(SelectorVisitor)
     }
 }
@@ -118,7 +375,7 @@ impl Visitor<'_> for SelectorVisitor {
     where
         E: de::Error,
     {
-        FromStr::from_str(v).map_err(de::Error::custom)
+        Ok(RuleSelector::from_str_lenient(v))
     }
 }
 
@@ -140,6 +397,19 @@ impl IntoIterator for &RuleSelector {
             RuleSelector::All => RuleSelectorIter::All(Rule::iter()),
             RuleSelector::Linter(linter) => RuleSelectorIter::Vec(linter.into_iter()),
             RuleSelector::Prefix { prefix, .. } => RuleSelectorIter::Vec(prefix.into_iter()),
+            RuleSelector::Category(category) => RuleSelectorIter::Vec(
+                Rule::iter()
+                    .filter(|rule| rule.categories().contains(category))
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            ),
+            RuleSelector::Pattern { glob, .. } => RuleSelectorIter::Vec(
+                Rule::iter()
+                    .filter(|rule| glob_matches(glob, &rule.noqa_code().to_string()))
+                    .collect::<Vec<_>>()
+                    .into_iter(),
+            ),
+            RuleSelector::Unknown(_) => RuleSelectorIter::Vec(Vec::new().into_iter()),
         }
     }
 }
@@ -178,27 +448,34 @@ impl JsonSchema for RuleSelector {
     }
 
     fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
-        Schema::Object(SchemaObject {
+        let known = SchemaObject {
             instance_type: Some(InstanceType::String.into()),
             enum_values: Some(
-                std::iter::once("ALL".to_string())
-                    .chain(
-                        RuleCodePrefix::iter()
-                            .map(|p| {
-                                let prefix = p.linter().common_prefix();
-                                let code = p.short_code();
-                                format!("{prefix}{code}")
-                            })
-                            .chain(Linter::iter().filter_map(|l| {
-                                let prefix = l.common_prefix();
-                                (!prefix.is_empty()).then(|| prefix.to_string())
-                            }))
-                            .sorted(),
-                    )
+                all_selector_candidates()
+                    .sorted()
                     .map(Value::String)
                     .collect(),
             ),
             ..SchemaObject::default()
+        };
+
+        // Relax the schema to also accept `*`-wildcard selectors like `E5*`, which can't be
+        // enumerated up front the way the known codes and categories above can.
+        let pattern = SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            string: Some(Box::new(schemars::schema::StringValidation {
+                pattern: Some(r"^[A-Za-z0-9]*\*[A-Za-z0-9*]*$".to_string()),
+                ..schemars::schema::StringValidation::default()
+            })),
+            ..SchemaObject::default()
+        };
+
+        Schema::Object(SchemaObject {
+            subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+                any_of: Some(vec![Schema::Object(known), Schema::Object(pattern)]),
+                ..schemars::schema::SubschemaValidation::default()
+            })),
+            ..SchemaObject::default()
         })
     }
 }
@@ -207,6 +484,7 @@ impl RuleSelector {
     pub(crate) fn specificity(&self) -> Specificity {
         match self {
             RuleSelector::All => Specificity::All,
+            RuleSelector::Category(..) => Specificity::Category,
             RuleSelector::Linter(..) => Specificity::Linter,
             RuleSelector::Prefix { prefix, .. } => {
                 let prefix: &'static str = prefix.short_code();
@@ -219,13 +497,35 @@ impl RuleSelector {
                     _ => panic!("RuleSelector::specificity doesn't yet support codes with so many characters"),
                 }
             }
+            // A glob is as specific as its literal prefix before the first `*`, measured like
+            // an exact code (without the linter's common prefix), so `E5*` sorts (and thus
+            // overrides/is-overridden) like a 2-char code and `UP00*` like the exact `UP00`.
+            // This only orders `Pattern` against other selectors by `Specificity`; a tie with
+            // an exact code of equal length isn't broken here and is up to whatever resolves a
+            // selector list (e.g. last-one-wins), not to parsing order.
+            RuleSelector::Pattern { prefix, .. } => {
+                let code_len =
+                    Linter::parse_code(prefix).map_or(prefix.len(), |(_, code)| code.len());
+                match code_len {
+                    0 => Specificity::Linter,
+                    1 => Specificity::Code1Char,
+                    2 => Specificity::Code2Chars,
+                    3 => Specificity::Code3Chars,
+                    4 => Specificity::Code4Chars,
+                    _ => Specificity::Code5Chars,
+                }
+            }
+            // Unrecognized selectors expand to no rules, so whichever rung they sit on can't
+            // change which rules end up selected or ignored.
+            RuleSelector::Unknown(_) => Specificity::Code5Chars,
         }
     }
 }
 
-#[derive(EnumIter, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, EnumIter, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) enum Specificity {
     All,
+    Category,
     Linter,
     Code1Char,
     Code2Chars,
@@ -233,3 +533,89 @@ pub(crate) enum Specificity {
     Code4Chars,
     Code5Chars,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_matches_literal() {
+        assert!(glob_matches("E501", "E501"));
+        assert!(!glob_matches("E501", "E502"));
+    }
+
+    #[test]
+    fn glob_matches_trailing_star() {
+        assert!(glob_matches("E5*", "E501"));
+        assert!(glob_matches("E5*", "E5"));
+        assert!(!glob_matches("E5*", "E4"));
+    }
+
+    #[test]
+    fn glob_matches_leading_and_multiple_stars() {
+        assert!(glob_matches("*501", "E501"));
+        assert!(glob_matches("*501", "W501"));
+        assert!(glob_matches("E*0*", "E501"));
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("*", "anything"));
+    }
+
+    #[test]
+    fn glob_matches_empty_string() {
+        assert!(glob_matches("", ""));
+        assert!(!glob_matches("", "E501"));
+        assert!(!glob_matches("E501", ""));
+    }
+
+    #[test]
+    fn glob_matches_no_match() {
+        assert!(!glob_matches("UP00*", "E501"));
+    }
+
+    #[test]
+    fn edit_distance_basics() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("E501", "E501"), 0);
+        assert_eq!(edit_distance("E501", "E502"), 1);
+        assert_eq!(edit_distance("E501", "W501"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn find_suggestions_contains_all_for_close_typo() {
+        // "ALL" is always a candidate (it doesn't depend on the rule registry), so a
+        // near-miss typo of it should always surface it as a suggestion.
+        assert!(find_suggestions("ALLL").contains(&"ALL".to_string()));
+    }
+
+    #[test]
+    fn find_suggestions_no_match_for_unrelated_input() {
+        assert!(find_suggestions("completely-unrelated-selector-text").is_empty());
+    }
+
+    #[test]
+    fn glob_selector_parses_with_its_literal_prefix() {
+        let RuleSelector::Pattern { glob, prefix } = RuleSelector::from_str("E5*").unwrap() else {
+            panic!("expected `E5*` to parse as a `RuleSelector::Pattern`")
+        };
+        assert_eq!(glob, "E5*");
+        assert_eq!(prefix, "E5");
+    }
+
+    #[test]
+    fn glob_selector_loses_to_a_longer_exact_code() {
+        // `E5*`'s specificity is derived purely from its 2-character literal prefix.
+        assert_eq!(
+            RuleSelector::from_str("E5*").unwrap().specificity(),
+            Specificity::Code2Chars
+        );
+
+        // An exact code selector's specificity is derived purely from its code length (see
+        // the `Prefix` arm of `RuleSelector::specificity`, which doesn't otherwise depend on
+        // rule data), so a 4-character code like `E501` resolves to `Code4Chars` independent
+        // of which rules actually exist. `Code2Chars < Code4Chars`, so whatever resolves a
+        // `select`/`ignore` list by specificity ranks the exact code above the broader glob.
+        assert!(Specificity::Code2Chars < Specificity::Code4Chars);
+    }
+}